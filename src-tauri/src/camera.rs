@@ -1,38 +1,465 @@
 use base64::{engine::general_purpose::STANDARD, Engine};
+use gphoto2::Context as GphotoContext;
 use nokhwa::{
     pixel_format::RgbFormat,
-    utils::{CameraIndex, RequestedFormat, RequestedFormatType, Resolution},
-    Camera,
+    utils::{
+        CameraIndex, ControlValueSetter, KnownCameraControl, RequestedFormat,
+        RequestedFormatType, Resolution,
+    },
+    Camera as NokhwaCamera,
 };
 use serde::{Deserialize, Serialize};
 use std::io::Cursor;
-use std::sync::mpsc::{self, Sender, Receiver};
+use std::sync::mpsc::{self, Sender, Receiver, TryRecvError};
 use std::sync::Mutex;
 use std::thread;
 use std::time::Duration;
-use tauri::State;
+use tauri::{AppHandle, Emitter, State};
+
+/// Tauri event emitted with each streamed preview frame's base64 JPEG data URL
+const PREVIEW_FRAME_EVENT: &str = "camera://preview-frame";
+/// Tauri event emitted once a camera is detected as unplugged/unresponsive
+const DISCONNECTED_EVENT: &str = "camera://disconnected";
+/// Consecutive capture failures after which the camera is treated as disconnected
+const MAX_CONSECUTIVE_FAILURES: u32 = 5;
+
+/// Device id prefix used for webcams driven through nokhwa
+const NOKHWA_PREFIX: &str = "nokhwa:";
+/// Device id prefix used for tethered DSLR/mirrorless cameras driven through libgphoto2
+const GPHOTO2_PREFIX: &str = "gphoto2:";
+
+/// A camera driver capable of producing still frames. `NokhwaBackend` drives
+/// webcams via video4linux/UVC; `GphotoBackend` drives tethered DSLR/mirrorless
+/// cameras over PTP/USB via libgphoto2. `camera_thread` talks to whichever
+/// backend `start_camera` selected without needing to know which one it is.
+trait CaptureBackend {
+    /// Capture one still frame with `overrides` applied for just this shot,
+    /// returned as a base64 JPEG data URL
+    fn capture(&mut self, quality: u8, overrides: &CaptureOverrides) -> Result<String, String>;
+
+    /// Current status of the underlying device
+    fn status(&self) -> CameraStatus;
+
+    /// Release the device
+    fn stop(&mut self);
+
+    /// Supported manual controls (exposure, ISO, white balance, focus, ...)
+    /// with their reported range and current value
+    fn controls(&self) -> Result<Vec<CameraControlInfo>, String> {
+        Err("Manual controls are not supported by this backend".to_string())
+    }
+
+    /// Persistently apply manual control values (as opposed to `capture`'s
+    /// per-shot overrides, which only apply for the duration of one frame)
+    fn set_controls(&mut self, _settings: &[CameraControlSetting]) -> Result<(), String> {
+        Err("Manual controls are not supported by this backend".to_string())
+    }
+
+    /// Whether `capture` is cheap and non-destructive enough to be called in
+    /// a tight loop for `start_preview_stream`. `false` backends reject
+    /// `StartStream` instead of being driven that way.
+    fn supports_streaming(&self) -> bool {
+        true
+    }
+}
+
+/// Per-shot overrides applied to the live camera immediately before a
+/// `Capture` and restored immediately after, so a burst and a single still
+/// can each submit their own settings bundle without mutating global state.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CaptureOverrides {
+    pub brightness: Option<i64>,
+    pub exposure: Option<i64>,
+    pub white_balance: Option<i64>,
+    pub resolution: Option<(u32, u32)>,
+}
+
+impl CaptureOverrides {
+    fn is_empty(&self) -> bool {
+        self.brightness.is_none()
+            && self.exposure.is_none()
+            && self.white_balance.is_none()
+            && self.resolution.is_none()
+    }
+}
+
+/// A single supported manual camera control, as reported by the device
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CameraControlInfo {
+    /// Stable key used by `set_camera_controls` (e.g. `"brightness"`, `"exposure"`)
+    pub key: String,
+    pub name: String,
+    pub min: i64,
+    pub max: i64,
+    pub step: i64,
+    pub current: i64,
+    pub default: i64,
+}
+
+/// A manual control value requested via `set_camera_controls`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CameraControlSetting {
+    pub key: String,
+    pub value: i64,
+}
+
+/// Map a nokhwa control to the stable key used in `CameraControlInfo`/`CameraControlSetting`
+fn control_key(control: KnownCameraControl) -> String {
+    match control {
+        KnownCameraControl::Brightness => "brightness".to_string(),
+        KnownCameraControl::Contrast => "contrast".to_string(),
+        KnownCameraControl::Hue => "hue".to_string(),
+        KnownCameraControl::Saturation => "saturation".to_string(),
+        KnownCameraControl::Sharpness => "sharpness".to_string(),
+        KnownCameraControl::Gamma => "gamma".to_string(),
+        KnownCameraControl::WhiteBalance => "white_balance".to_string(),
+        KnownCameraControl::BacklightComp => "backlight_comp".to_string(),
+        KnownCameraControl::Gain => "gain".to_string(),
+        KnownCameraControl::Pan => "pan".to_string(),
+        KnownCameraControl::Tilt => "tilt".to_string(),
+        KnownCameraControl::Zoom => "zoom".to_string(),
+        KnownCameraControl::Exposure => "exposure".to_string(),
+        KnownCameraControl::Iris => "iris".to_string(),
+        KnownCameraControl::Focus => "focus".to_string(),
+        KnownCameraControl::Other(id) => format!("other_{}", id),
+    }
+}
+
+/// Webcam backend built on nokhwa
+struct NokhwaBackend {
+    camera: NokhwaCamera,
+}
+
+impl NokhwaBackend {
+    fn open(device_id: Option<&str>) -> Result<Self, String> {
+        let index = match device_id {
+            Some(id) => {
+                let idx: u32 = id.parse().unwrap_or(0);
+                CameraIndex::Index(idx)
+            }
+            None => CameraIndex::Index(0),
+        };
+
+        let requested = RequestedFormat::new::<RgbFormat>(
+            RequestedFormatType::HighestResolution(Resolution::new(1920, 1080))
+        );
+
+        let mut camera = NokhwaCamera::new(index, requested)
+            .map_err(|e| format!("Failed to create camera: {}", e))?;
+
+        camera.open_stream()
+            .map_err(|e| format!("Failed to open camera stream: {}", e))?;
+
+        Ok(Self { camera })
+    }
+
+    /// Grab and JPEG-encode one frame at the camera's current settings
+    fn capture_plain(&mut self, quality: u8) -> Result<String, String> {
+        let frame = self.camera.frame()
+            .map_err(|e| format!("Failed to capture frame: {}", e))?;
+
+        // Use decode_image which properly handles format conversion
+        let img = frame.decode_image::<RgbFormat>()
+            .map_err(|e| format!("Failed to decode frame: {}", e))?;
+
+        let mut jpeg_buffer = Cursor::new(Vec::new());
+
+        image::codecs::jpeg::JpegEncoder::new_with_quality(&mut jpeg_buffer, quality)
+            .encode_image(&img)
+            .map_err(|e| format!("Failed to encode JPEG: {}", e))?;
+
+        let base64_data = STANDARD.encode(jpeg_buffer.into_inner());
+        Ok(format!("data:image/jpeg;base64,{}", base64_data))
+    }
+
+    fn known_controls(&self) -> Result<Vec<nokhwa::utils::CameraControl>, String> {
+        self.camera.camera_controls()
+            .map_err(|e| format!("Failed to read camera controls: {}", e))
+    }
+}
+
+impl CaptureBackend for NokhwaBackend {
+    fn capture(&mut self, quality: u8, overrides: &CaptureOverrides) -> Result<String, String> {
+        if overrides.is_empty() {
+            return self.capture_plain(quality);
+        }
+
+        // Validate against the reported ranges up front, same as `set_controls`,
+        // before touching the live device.
+        let known = self.known_controls()?;
+        let mut to_apply: Vec<(KnownCameraControl, i64)> = Vec::new();
+        let mut unsupported = Vec::new();
+
+        for (control, value) in [
+            (KnownCameraControl::Brightness, overrides.brightness),
+            (KnownCameraControl::Exposure, overrides.exposure),
+            (KnownCameraControl::WhiteBalance, overrides.white_balance),
+        ] {
+            let Some(value) = value else { continue };
+            match known.iter().find(|c| c.control() == control) {
+                Some(info) if (info.min()..=info.max()).contains(&value) => {
+                    to_apply.push((control, value));
+                }
+                Some(info) => unsupported.push(format!(
+                    "{} (must be between {} and {})",
+                    control_key(control), info.min(), info.max()
+                )),
+                None => unsupported.push(control_key(control)),
+            }
+        }
+
+        if !unsupported.is_empty() {
+            return Err(format!("Unsupported controls: {}", unsupported.join(", ")));
+        }
+
+        let mut restore_controls: Vec<(KnownCameraControl, i64)> = Vec::new();
+        let mut restore_resolution: Option<Resolution> = None;
+        let mut apply_error: Option<String> = None;
+
+        for (control, value) in to_apply {
+            let current = match self.camera.camera_control(control) {
+                Ok(c) => c,
+                Err(e) => {
+                    apply_error = Some(format!("Failed to read {:?}: {}", control, e));
+                    break;
+                }
+            };
+            match self.camera.set_camera_control(control, ControlValueSetter::Integer(value)) {
+                Ok(()) => restore_controls.push((control, current.value())),
+                Err(e) => {
+                    apply_error = Some(format!("Failed to set {:?}: {}", control, e));
+                    break;
+                }
+            }
+        }
+
+        if apply_error.is_none() {
+            if let Some((width, height)) = overrides.resolution {
+                restore_resolution = Some(self.camera.resolution());
+                if let Err(e) = self.camera.set_resolution(Resolution::new(width, height)) {
+                    apply_error = Some(format!("Failed to set resolution: {}", e));
+                }
+            }
+        }
+
+        let result = match &apply_error {
+            None => self.capture_plain(quality),
+            Some(e) => Err(e.clone()),
+        };
+
+        // Restore whatever we actually changed regardless of whether an
+        // override or the shot itself failed partway through.
+        for (control, value) in restore_controls {
+            self.camera.set_camera_control(control, ControlValueSetter::Integer(value)).ok();
+        }
+        if let Some(resolution) = restore_resolution {
+            self.camera.set_resolution(resolution).ok();
+        }
+
+        result
+    }
+
+    fn status(&self) -> CameraStatus {
+        let resolution = self.camera.resolution();
+        CameraStatus {
+            is_active: true,
+            device_name: Some(self.camera.info().human_name().to_string()),
+            resolution: Some((resolution.width(), resolution.height())),
+            backend: Some("nokhwa".to_string()),
+        }
+    }
+
+    fn stop(&mut self) {
+        self.camera.stop_stream().ok();
+    }
+
+    fn controls(&self) -> Result<Vec<CameraControlInfo>, String> {
+        let controls = self.known_controls()?;
+        Ok(controls.iter().map(|c| CameraControlInfo {
+            key: control_key(c.control()),
+            name: c.name().to_string(),
+            min: c.min(),
+            max: c.max(),
+            step: c.step(),
+            current: c.value(),
+            default: c.default(),
+        }).collect())
+    }
+
+    fn set_controls(&mut self, settings: &[CameraControlSetting]) -> Result<(), String> {
+        let known = self.known_controls()?;
+        let mut to_apply = Vec::new();
+        let mut unsupported = Vec::new();
+
+        for setting in settings {
+            match known.iter().find(|c| control_key(c.control()) == setting.key) {
+                Some(control) if (control.min()..=control.max()).contains(&setting.value) => {
+                    to_apply.push((control.control(), setting.value));
+                }
+                Some(control) => unsupported.push(format!(
+                    "{} (must be between {} and {})",
+                    setting.key, control.min(), control.max()
+                )),
+                None => unsupported.push(setting.key.clone()),
+            }
+        }
+
+        if !unsupported.is_empty() {
+            return Err(format!("Unsupported controls: {}", unsupported.join(", ")));
+        }
+
+        for (control, value) in to_apply {
+            self.camera.set_camera_control(control, ControlValueSetter::Integer(value))
+                .map_err(|e| format!("Failed to set {:?}: {}", control, e))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Tethered DSLR/mirrorless backend built on libgphoto2
+struct GphotoBackend {
+    camera: gphoto2::Camera,
+    device_name: String,
+}
+
+impl GphotoBackend {
+    fn open(port: Option<&str>) -> Result<Self, String> {
+        let context = GphotoContext::new()
+            .map_err(|e| format!("Failed to init gphoto2 context: {}", e))?;
+
+        let camera = match port {
+            Some(port) => context
+                .get_camera(port)
+                .map_err(|e| format!("Failed to open tethered camera at {}: {}", port, e))?,
+            None => context
+                .autodetect_camera()
+                .map_err(|e| format!("No tethered camera detected: {}", e))?,
+        };
+
+        let device_name = camera
+            .abilities()
+            .map(|a| a.model().to_string())
+            .unwrap_or_else(|_| "Tethered camera".to_string());
+
+        Ok(Self { camera, device_name })
+    }
+}
+
+impl CaptureBackend for GphotoBackend {
+    fn capture(&mut self, _quality: u8, overrides: &CaptureOverrides) -> Result<String, String> {
+        if !overrides.is_empty() {
+            return Err("Per-shot overrides are not supported by the gphoto2 backend".to_string());
+        }
+
+        let file_path = self.camera.capture_image()
+            .map_err(|e| format!("Failed to trigger shutter: {}", e))?;
+
+        let file = self.camera
+            .fs()
+            .download(&file_path.folder(), &file_path.name())
+            .map_err(|e| format!("Failed to download captured image: {}", e))?;
+
+        let bytes = file.get_data()
+            .map_err(|e| format!("Failed to read captured image data: {}", e))?;
+
+        let base64_data = STANDARD.encode(bytes);
+        Ok(format!("data:image/jpeg;base64,{}", base64_data))
+    }
+
+    fn status(&self) -> CameraStatus {
+        CameraStatus {
+            is_active: true,
+            device_name: Some(self.device_name.clone()),
+            resolution: None,
+            backend: Some("gphoto2".to_string()),
+        }
+    }
+
+    fn stop(&mut self) {
+        // The gphoto2 crate releases the camera handle on drop; nothing to flush here.
+    }
+
+    fn supports_streaming(&self) -> bool {
+        // `capture` fires a real shutter release per call; looping it for a
+        // live preview would fire the shutter at the stream's fps instead of
+        // grabbing cheap sensor frames.
+        false
+    }
+}
+
+/// Open the backend addressed by a prefixed device id (e.g. `nokhwa:0`,
+/// `gphoto2:usb:001,004`). An id with no known prefix is treated as a bare
+/// nokhwa index for backwards compatibility.
+fn open_backend(device_id: Option<String>) -> Result<Box<dyn CaptureBackend>, String> {
+    match device_id {
+        Some(id) if id.starts_with(GPHOTO2_PREFIX) => {
+            let port = id.trim_start_matches(GPHOTO2_PREFIX);
+            Ok(Box::new(GphotoBackend::open(Some(port))?))
+        }
+        Some(id) if id.starts_with(NOKHWA_PREFIX) => {
+            let index = id.trim_start_matches(NOKHWA_PREFIX);
+            Ok(Box::new(NokhwaBackend::open(Some(index))?))
+        }
+        Some(id) => Ok(Box::new(NokhwaBackend::open(Some(&id))?)),
+        None => Ok(Box::new(NokhwaBackend::open(None)?)),
+    }
+}
 
 /// Messages sent to the camera thread
 enum CameraCommand {
     Start { device_id: Option<String>, reply: Sender<Result<CameraStatus, String>> },
     Stop { reply: Sender<Result<(), String>> },
-    Capture { quality: u8, reply: Sender<Result<String, String>> },
+    Capture { quality: u8, overrides: CaptureOverrides, reply: Sender<Result<String, String>> },
     GetStatus { reply: Sender<Result<CameraStatus, String>> },
+    StartStream { fps: u32, quality: u8, reply: Sender<Result<(), String>> },
+    StopStream { reply: Sender<Result<(), String>> },
+    GetControls { reply: Sender<Result<Vec<CameraControlInfo>, String>> },
+    SetControls { settings: Vec<CameraControlSetting>, reply: Sender<Result<(), String>> },
+    CheckPresent { device_id: String, reply: Sender<Result<bool, String>> },
+}
+
+/// Re-query connected devices to check whether `device_id` (as returned by
+/// `list_cameras`) is still present, without disturbing an active session.
+fn is_device_present(device_id: &str) -> Result<bool, String> {
+    if let Some(port) = device_id.strip_prefix(GPHOTO2_PREFIX) {
+        let context = GphotoContext::new()
+            .map_err(|e| format!("Failed to init gphoto2 context: {}", e))?;
+        let tethered = context.list_cameras()
+            .map_err(|e| format!("Failed to query tethered cameras: {}", e))?;
+        return Ok(tethered.iter().any(|(_, p)| p == port));
+    }
+
+    let index = device_id.strip_prefix(NOKHWA_PREFIX).unwrap_or(device_id);
+    let devices = nokhwa::query(nokhwa::utils::ApiBackend::Auto)
+        .map_err(|e| format!("Failed to query webcams: {}", e))?;
+    Ok(devices.iter().any(|info| info.index().to_string() == index))
 }
 
 /// Camera state managed by Tauri - holds a channel to the camera thread
 pub struct CameraState {
     sender: Mutex<Option<Sender<CameraCommand>>>,
+    app_handle: Mutex<Option<AppHandle>>,
 }
 
 impl Default for CameraState {
     fn default() -> Self {
         Self {
             sender: Mutex::new(None),
+            app_handle: Mutex::new(None),
         }
     }
 }
 
+impl CameraState {
+    /// Record the app handle so the camera thread can emit preview-frame events.
+    /// Must be called during `run()`'s `.setup` before any camera command is issued.
+    pub fn set_app_handle(&self, handle: AppHandle) -> Result<(), String> {
+        *self.app_handle.lock().map_err(|e| format!("Lock error: {}", e))? = Some(handle);
+        Ok(())
+    }
+}
+
 /// Camera device info returned to frontend
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CameraDevice {
@@ -46,145 +473,248 @@ pub struct CameraStatus {
     pub is_active: bool,
     pub device_name: Option<String>,
     pub resolution: Option<(u32, u32)>,
+    /// Which backend/driver is serving the active camera (`"nokhwa"` or `"gphoto2"`)
+    pub backend: Option<String>,
 }
 
-/// Camera thread that owns the non-Send Camera
-fn camera_thread(receiver: Receiver<CameraCommand>) {
-    let mut camera: Option<Camera> = None;
-    
-    while let Ok(cmd) = receiver.recv() {
-        match cmd {
-            CameraCommand::Start { device_id, reply } => {
-                // Stop existing camera if any
-                if let Some(mut cam) = camera.take() {
-                    cam.stop_stream().ok();
-                }
-                
-                let result = (|| -> Result<CameraStatus, String> {
-                    let index = match device_id {
-                        Some(id) => {
-                            let idx: u32 = id.parse().unwrap_or(0);
-                            CameraIndex::Index(idx)
-                        }
-                        None => CameraIndex::Index(0),
-                    };
-                    
-                    let requested = RequestedFormat::new::<RgbFormat>(
-                        RequestedFormatType::HighestResolution(Resolution::new(1920, 1080))
-                    );
-                    
-                    let mut cam = Camera::new(index, requested)
-                        .map_err(|e| format!("Failed to create camera: {}", e))?;
-                    
-                    cam.open_stream()
-                        .map_err(|e| format!("Failed to open camera stream: {}", e))?;
-                    
-                    let resolution = cam.resolution();
-                    let device_name = cam.info().human_name().to_string();
-                    
-                    let status = CameraStatus {
-                        is_active: true,
-                        device_name: Some(device_name),
-                        resolution: Some((resolution.width(), resolution.height())),
-                    };
-                    
-                    camera = Some(cam);
-                    Ok(status)
-                })();
-                
-                reply.send(result).ok();
+/// Active live-stream parameters while `StartStream` is in effect
+#[derive(Clone, Copy)]
+struct StreamConfig {
+    fps: u32,
+    quality: u8,
+}
+
+/// Drop the active backend and tell the frontend it's gone, so the UI can
+/// offer a clean "reconnect" flow instead of silently failing forever.
+fn disconnect(
+    camera: &mut Option<Box<dyn CaptureBackend>>,
+    streaming: &mut Option<StreamConfig>,
+    failures: &mut u32,
+    app_handle: &AppHandle,
+) {
+    if let Some(mut backend) = camera.take() {
+        backend.stop();
+    }
+    *streaming = None;
+    *failures = 0;
+    app_handle.emit(DISCONNECTED_EVENT, ()).ok();
+}
+
+/// Track a capture attempt's outcome and treat `MAX_CONSECUTIVE_FAILURES` in
+/// a row as the camera having been unplugged.
+fn note_capture_outcome(
+    result: &Result<String, String>,
+    camera: &mut Option<Box<dyn CaptureBackend>>,
+    streaming: &mut Option<StreamConfig>,
+    failures: &mut u32,
+    app_handle: &AppHandle,
+) {
+    match result {
+        Ok(_) => *failures = 0,
+        Err(_) => {
+            *failures += 1;
+            if *failures >= MAX_CONSECUTIVE_FAILURES {
+                log::warn!("Camera appears disconnected after {} consecutive failures", *failures);
+                disconnect(camera, streaming, failures, app_handle);
+            }
+        }
+    }
+}
+
+/// Handle a single command against the thread-local camera state.
+fn handle_camera_command(
+    cmd: CameraCommand,
+    camera: &mut Option<Box<dyn CaptureBackend>>,
+    streaming: &mut Option<StreamConfig>,
+    failures: &mut u32,
+    app_handle: &AppHandle,
+) {
+    match cmd {
+        CameraCommand::Start { device_id, reply } => {
+            // Always fully stop and drop any existing stream before opening a new one
+            if let Some(mut backend) = camera.take() {
+                backend.stop();
+            }
+            *streaming = None;
+            *failures = 0;
+
+            let result = open_backend(device_id).map(|backend| {
+                let status = backend.status();
+                *camera = Some(backend);
+                status
+            });
+
+            reply.send(result).ok();
+        }
+
+        CameraCommand::Stop { reply } => {
+            if let Some(mut backend) = camera.take() {
+                backend.stop();
             }
-            
-            CameraCommand::Stop { reply } => {
-                if let Some(mut cam) = camera.take() {
-                    cam.stop_stream().ok();
+            *streaming = None;
+            *failures = 0;
+            reply.send(Ok(())).ok();
+        }
+
+        CameraCommand::Capture { quality, overrides, reply } => {
+            let had_camera = camera.is_some();
+            let result = camera.as_mut()
+                .ok_or_else(|| "Camera not started".to_string())
+                .and_then(|backend| backend.capture(quality, &overrides));
+
+            if had_camera {
+                note_capture_outcome(&result, camera, streaming, failures, app_handle);
+            }
+
+            reply.send(result).ok();
+        }
+
+        CameraCommand::GetStatus { reply } => {
+            let status = match camera {
+                Some(backend) => backend.status(),
+                None => CameraStatus {
+                    is_active: false,
+                    device_name: None,
+                    resolution: None,
+                    backend: None,
+                },
+            };
+            reply.send(Ok(status)).ok();
+        }
+
+        CameraCommand::StartStream { fps, quality, reply } => {
+            match camera.as_deref() {
+                None => {
+                    reply.send(Err("Camera not started".to_string())).ok();
+                }
+                Some(backend) if !backend.supports_streaming() => {
+                    reply.send(Err("Live preview streaming is not supported by this backend".to_string())).ok();
+                }
+                Some(_) => {
+                    *streaming = Some(StreamConfig { fps: fps.max(1), quality });
+                    reply.send(Ok(())).ok();
                 }
-                reply.send(Ok(())).ok();
             }
-            
-            CameraCommand::Capture { quality, reply } => {
-                let result = (|| -> Result<String, String> {
-                    let cam = camera.as_mut()
-                        .ok_or_else(|| "Camera not started".to_string())?;
-                    
-                    let frame = cam.frame()
-                        .map_err(|e| format!("Failed to capture frame: {}", e))?;
-                    
-                    // Use decode_image which properly handles format conversion
-                    let img = frame.decode_image::<RgbFormat>()
-                        .map_err(|e| format!("Failed to decode frame: {}", e))?;
-                    
-                    let mut jpeg_buffer = Cursor::new(Vec::new());
-                    
-                    image::codecs::jpeg::JpegEncoder::new_with_quality(&mut jpeg_buffer, quality)
-                        .encode_image(&img)
-                        .map_err(|e| format!("Failed to encode JPEG: {}", e))?;
-                    
-                    let base64_data = STANDARD.encode(jpeg_buffer.into_inner());
-                    let data_url = format!("data:image/jpeg;base64,{}", base64_data);
-                    
-                    Ok(data_url)
-                })();
-                
-                reply.send(result).ok();
+        }
+
+        CameraCommand::StopStream { reply } => {
+            *streaming = None;
+            reply.send(Ok(())).ok();
+        }
+
+        CameraCommand::GetControls { reply } => {
+            let result = camera.as_ref()
+                .ok_or_else(|| "Camera not started".to_string())
+                .and_then(|backend| backend.controls());
+            reply.send(result).ok();
+        }
+
+        CameraCommand::SetControls { settings, reply } => {
+            let result = camera.as_mut()
+                .ok_or_else(|| "Camera not started".to_string())
+                .and_then(|backend| backend.set_controls(&settings));
+            reply.send(result).ok();
+        }
+
+        CameraCommand::CheckPresent { device_id, reply } => {
+            reply.send(is_device_present(&device_id)).ok();
+        }
+    }
+}
+
+/// Camera thread that owns the non-Send backend and, while streaming, pushes
+/// preview frames as `camera://preview-frame` events between drained commands
+fn camera_thread(receiver: Receiver<CameraCommand>, app_handle: AppHandle) {
+    let mut camera: Option<Box<dyn CaptureBackend>> = None;
+    let mut streaming: Option<StreamConfig> = None;
+    let mut failures: u32 = 0;
+
+    loop {
+        let Some(cfg) = streaming else {
+            match receiver.recv() {
+                Ok(cmd) => handle_camera_command(cmd, &mut camera, &mut streaming, &mut failures, &app_handle),
+                Err(_) => break,
             }
-            
-            CameraCommand::GetStatus { reply } => {
-                let status = match &camera {
-                    Some(cam) => {
-                        let resolution = cam.resolution();
-                        CameraStatus {
-                            is_active: true,
-                            device_name: Some(cam.info().human_name().to_string()),
-                            resolution: Some((resolution.width(), resolution.height())),
-                        }
-                    }
-                    None => CameraStatus {
-                        is_active: false,
-                        device_name: None,
-                        resolution: None,
-                    },
-                };
-                reply.send(Ok(status)).ok();
+            continue;
+        };
+
+        match receiver.try_recv() {
+            Ok(cmd) => {
+                handle_camera_command(cmd, &mut camera, &mut streaming, &mut failures, &app_handle);
+                continue;
+            }
+            Err(TryRecvError::Disconnected) => break,
+            Err(TryRecvError::Empty) => {}
+        }
+
+        if camera.is_some() {
+            let result = camera.as_mut().unwrap().capture(cfg.quality, &CaptureOverrides::default());
+            match &result {
+                Ok(data_url) => {
+                    app_handle.emit(PREVIEW_FRAME_EVENT, data_url).ok();
+                }
+                Err(e) => log::warn!("Preview stream frame failed: {}", e),
             }
+            note_capture_outcome(&result, &mut camera, &mut streaming, &mut failures, &app_handle);
+        } else {
+            streaming = None;
         }
+
+        thread::sleep(Duration::from_millis(1000 / cfg.fps as u64));
     }
-    
+
     // Cleanup on thread exit
-    if let Some(mut cam) = camera.take() {
-        cam.stop_stream().ok();
+    if let Some(mut backend) = camera.take() {
+        backend.stop();
     }
 }
 
 /// Ensure camera thread is running and get sender
 fn get_or_create_sender(state: &CameraState) -> Result<Sender<CameraCommand>, String> {
     let mut sender_guard = state.sender.lock().map_err(|e| format!("Lock error: {}", e))?;
-    
+
     if sender_guard.is_none() {
+        let app_handle = state.app_handle.lock().map_err(|e| format!("Lock error: {}", e))?
+            .clone()
+            .ok_or_else(|| "App handle not initialized".to_string())?;
+
         let (tx, rx) = mpsc::channel();
-        thread::spawn(move || camera_thread(rx));
+        thread::spawn(move || camera_thread(rx, app_handle));
         *sender_guard = Some(tx);
     }
-    
+
     sender_guard.clone().ok_or_else(|| "Failed to get sender".to_string())
 }
 
-/// List all available cameras
+/// List all available cameras across both backends. Webcam ids are prefixed
+/// with `nokhwa:`, tethered DSLR/mirrorless ids with `gphoto2:`, so
+/// `start_camera` knows which backend to route to.
 #[tauri::command]
 pub fn list_cameras() -> Result<Vec<CameraDevice>, String> {
     log::info!("Listing available cameras");
-    
-    let devices = nokhwa::query(nokhwa::utils::ApiBackend::Auto)
-        .map_err(|e| format!("Failed to query cameras: {}", e))?;
-    
-    let cameras: Vec<CameraDevice> = devices
-        .iter()
-        .map(|info| CameraDevice {
-            id: info.index().to_string(),
-            name: info.human_name().to_string(),
-        })
-        .collect();
-    
+
+    let mut cameras = Vec::new();
+
+    match nokhwa::query(nokhwa::utils::ApiBackend::Auto) {
+        Ok(devices) => {
+            cameras.extend(devices.iter().map(|info| CameraDevice {
+                id: format!("{}{}", NOKHWA_PREFIX, info.index()),
+                name: info.human_name().to_string(),
+            }));
+        }
+        Err(e) => log::warn!("Failed to query webcams: {}", e),
+    }
+
+    match GphotoContext::new().and_then(|ctx| ctx.list_cameras()) {
+        Ok(tethered) => {
+            cameras.extend(tethered.into_iter().map(|(name, port)| CameraDevice {
+                id: format!("{}{}", GPHOTO2_PREFIX, port),
+                name,
+            }));
+        }
+        Err(e) => log::warn!("Failed to query tethered cameras: {}", e),
+    }
+
     log::info!("Found {} cameras", cameras.len());
     Ok(cameras)
 }
@@ -235,17 +765,38 @@ pub fn get_camera_status(state: State<'_, CameraState>) -> Result<CameraStatus,
         .map_err(|e| format!("Camera command timeout: {}", e))?
 }
 
-/// Capture a single frame and return as base64 JPEG
+/// Check whether a previously-listed device is still physically present,
+/// without disturbing whatever the active session is doing
 #[tauri::command]
-pub fn capture_frame(state: State<'_, CameraState>, quality: Option<u8>) -> Result<String, String> {
+pub fn is_camera_present(state: State<'_, CameraState>, device_id: String) -> Result<bool, String> {
     let sender = get_or_create_sender(&state)?;
     let (reply_tx, reply_rx) = mpsc::channel();
-    
+
+    sender.send(CameraCommand::CheckPresent { device_id, reply: reply_tx })
+        .map_err(|e| format!("Failed to send command: {}", e))?;
+
+    reply_rx.recv_timeout(Duration::from_secs(2))
+        .map_err(|e| format!("Camera command timeout: {}", e))?
+}
+
+/// Capture a single frame and return as base64 JPEG. `overrides` carries
+/// optional per-shot settings (brightness, exposure/ISO, white balance,
+/// resolution) that apply only to this capture request.
+#[tauri::command]
+pub fn capture_frame(
+    state: State<'_, CameraState>,
+    quality: Option<u8>,
+    overrides: Option<CaptureOverrides>,
+) -> Result<String, String> {
+    let sender = get_or_create_sender(&state)?;
+    let (reply_tx, reply_rx) = mpsc::channel();
+
     let quality = quality.unwrap_or(90);
-    
-    sender.send(CameraCommand::Capture { quality, reply: reply_tx })
+    let overrides = overrides.unwrap_or_default();
+
+    sender.send(CameraCommand::Capture { quality, overrides, reply: reply_tx })
         .map_err(|e| format!("Failed to send command: {}", e))?;
-    
+
     reply_rx.recv_timeout(Duration::from_secs(2))
         .map_err(|e| format!("Camera command timeout: {}", e))?
 }
@@ -253,5 +804,77 @@ pub fn capture_frame(state: State<'_, CameraState>, quality: Option<u8>) -> Resu
 /// Get a preview frame (lower quality for live preview)
 #[tauri::command]
 pub fn get_preview_frame(state: State<'_, CameraState>) -> Result<String, String> {
-    capture_frame(state, Some(60))
+    capture_frame(state, Some(60), None)
+}
+
+/// Start pushing live preview frames to the frontend via `camera://preview-frame`
+/// events instead of polling `get_preview_frame` in a loop.
+#[tauri::command]
+pub fn start_preview_stream(
+    state: State<'_, CameraState>,
+    fps: Option<u32>,
+    quality: Option<u8>,
+) -> Result<(), String> {
+    log::info!("Starting preview stream");
+
+    let sender = get_or_create_sender(&state)?;
+    let (reply_tx, reply_rx) = mpsc::channel();
+
+    sender.send(CameraCommand::StartStream {
+        fps: fps.unwrap_or(15),
+        quality: quality.unwrap_or(60),
+        reply: reply_tx,
+    })
+        .map_err(|e| format!("Failed to send command: {}", e))?;
+
+    reply_rx.recv_timeout(Duration::from_secs(5))
+        .map_err(|e| format!("Camera command timeout: {}", e))?
+}
+
+/// Stop the live preview stream started by `start_preview_stream`
+#[tauri::command]
+pub fn stop_preview_stream(state: State<'_, CameraState>) -> Result<(), String> {
+    log::info!("Stopping preview stream");
+
+    let sender = get_or_create_sender(&state)?;
+    let (reply_tx, reply_rx) = mpsc::channel();
+
+    sender.send(CameraCommand::StopStream { reply: reply_tx })
+        .map_err(|e| format!("Failed to send command: {}", e))?;
+
+    reply_rx.recv_timeout(Duration::from_secs(5))
+        .map_err(|e| format!("Camera command timeout: {}", e))?
+}
+
+/// List supported manual controls (exposure, ISO, white balance, focus, ...)
+/// with each one's reported min/max/step and current value
+#[tauri::command]
+pub fn get_camera_controls(state: State<'_, CameraState>) -> Result<Vec<CameraControlInfo>, String> {
+    let sender = get_or_create_sender(&state)?;
+    let (reply_tx, reply_rx) = mpsc::channel();
+
+    sender.send(CameraCommand::GetControls { reply: reply_tx })
+        .map_err(|e| format!("Failed to send command: {}", e))?;
+
+    reply_rx.recv_timeout(Duration::from_secs(2))
+        .map_err(|e| format!("Camera command timeout: {}", e))?
+}
+
+/// Apply manual control values persistently. Unlike `capture_frame`'s
+/// `overrides`, these stick until changed again or the camera is restarted.
+/// Fails with a structured error listing any keys that are unknown or out
+/// of the control's reported range.
+#[tauri::command]
+pub fn set_camera_controls(
+    state: State<'_, CameraState>,
+    settings: Vec<CameraControlSetting>,
+) -> Result<(), String> {
+    let sender = get_or_create_sender(&state)?;
+    let (reply_tx, reply_rx) = mpsc::channel();
+
+    sender.send(CameraCommand::SetControls { settings, reply: reply_tx })
+        .map_err(|e| format!("Failed to send command: {}", e))?;
+
+    reply_rx.recv_timeout(Duration::from_secs(2))
+        .map_err(|e| format!("Camera command timeout: {}", e))?
 }
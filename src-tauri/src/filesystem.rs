@@ -1,6 +1,7 @@
 use std::fs;
 use std::path::PathBuf;
 use base64::{Engine as _, engine::general_purpose};
+use serde::Serialize;
 
 /// Save a base64-encoded file to disk in the given directory.
 /// Creates the directory recursively if it doesn't exist.
@@ -28,6 +29,65 @@ pub async fn save_file_to_disk(
     Ok(file_path.to_string_lossy().to_string())
 }
 
+/// Outcome of writing (or verifying) one file/directory in a multi-target batch
+#[derive(Debug, Clone, Serialize)]
+pub struct DirectoryResult {
+    pub path: String,
+    pub ok: bool,
+    pub error: Option<String>,
+}
+
+/// Save a base64-encoded file to several directories at once, reading each
+/// write back to confirm it landed rather than trusting it. A failing
+/// directory is reported in its own result entry instead of aborting the rest.
+#[tauri::command]
+pub async fn save_file_to_disk_multi(
+    dirs: Vec<String>,
+    file_name: String,
+    data_base64: String,
+) -> Result<Vec<DirectoryResult>, String> {
+    let bytes = general_purpose::STANDARD
+        .decode(&data_base64)
+        .map_err(|e| format!("Failed to decode base64: {}", e))?;
+
+    let mut results = Vec::with_capacity(dirs.len());
+
+    for dir_path in dirs {
+        let dir = PathBuf::from(&dir_path);
+        let file_path = dir.join(&file_name);
+
+        let outcome = (|| -> Result<(), String> {
+            fs::create_dir_all(&dir).map_err(|e| format!("Failed to create directory: {}", e))?;
+            fs::write(&file_path, &bytes).map_err(|e| format!("Failed to write file: {}", e))?;
+
+            let written = fs::read(&file_path).map_err(|e| format!("Failed to read back file: {}", e))?;
+            if written != bytes {
+                return Err(format!(
+                    "Write verification failed: read-back content did not match for {}",
+                    file_path.display()
+                ));
+            }
+
+            Ok(())
+        })();
+
+        results.push(match outcome {
+            Ok(()) => DirectoryResult {
+                path: file_path.to_string_lossy().to_string(),
+                ok: true,
+                error: None,
+            },
+            Err(e) => DirectoryResult {
+                path: file_path.to_string_lossy().to_string(),
+                ok: false,
+                error: Some(e),
+            },
+        });
+    }
+
+    Ok(results)
+}
+
 /// Open native OS folder picker dialog and return the selected path.
 #[tauri::command]
 pub async fn pick_directory() -> Result<Option<String>, String> {
@@ -59,3 +119,34 @@ pub async fn check_directory_writable(dir_path: String) -> Result<bool, String>
         Err(_) => Ok(false),
     }
 }
+
+/// Batch variant of `check_directory_writable`: checks every configured save
+/// destination independently so one unreachable volume (e.g. a disconnected
+/// USB drive) doesn't block reporting on the others.
+#[tauri::command]
+pub async fn check_directories_writable(dirs: Vec<String>) -> Result<Vec<DirectoryResult>, String> {
+    let mut results = Vec::with_capacity(dirs.len());
+
+    for dir_path in dirs {
+        let dir = PathBuf::from(&dir_path);
+
+        let outcome = (|| -> Result<(), String> {
+            if !dir.exists() {
+                fs::create_dir_all(&dir).map_err(|e| format!("Cannot create directory: {}", e))?;
+            }
+
+            let test_file = dir.join(".chronosnap_test");
+            fs::write(&test_file, b"test").map_err(|e| format!("Directory not writable: {}", e))?;
+            let _ = fs::remove_file(&test_file);
+
+            Ok(())
+        })();
+
+        results.push(match outcome {
+            Ok(()) => DirectoryResult { path: dir_path, ok: true, error: None },
+            Err(e) => DirectoryResult { path: dir_path, ok: false, error: Some(e) },
+        });
+    }
+
+    Ok(results)
+}
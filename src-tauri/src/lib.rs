@@ -3,11 +3,14 @@ mod camera;
 mod filesystem;
 
 use camera::CameraState;
+use printer::PrinterState;
+use tauri::Manager;
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
   tauri::Builder::default()
     .manage(CameraState::default())
+    .manage(PrinterState::default())
     .setup(|app| {
       if cfg!(debug_assertions) {
         app.handle().plugin(
@@ -16,6 +19,7 @@ pub fn run() {
             .build(),
         )?;
       }
+      app.state::<CameraState>().set_app_handle(app.handle().clone())?;
       Ok(())
     })
     .invoke_handler(tauri::generate_handler![
@@ -23,15 +27,25 @@ pub fn run() {
       printer::get_default_printer,
       printer::print_test_page,
       printer::print_photo,
+      printer::get_print_job_status,
+      printer::list_print_jobs,
+      printer::cancel_print_job,
       camera::list_cameras,
       camera::start_camera,
       camera::stop_camera,
       camera::get_camera_status,
+      camera::is_camera_present,
       camera::capture_frame,
       camera::get_preview_frame,
+      camera::start_preview_stream,
+      camera::stop_preview_stream,
+      camera::get_camera_controls,
+      camera::set_camera_controls,
       filesystem::save_file_to_disk,
+      filesystem::save_file_to_disk_multi,
       filesystem::pick_directory,
       filesystem::check_directory_writable,
+      filesystem::check_directories_writable,
     ])
     .run(tauri::generate_context!())
     .expect("error while running tauri application");
@@ -1,7 +1,12 @@
 use printers;
 use printers::common::base::job::PrinterJobOptions;
 use serde::Serialize;
-use tauri::command;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use tauri::{command, State};
 
 /// Printer info returned to the frontend
 #[derive(Debug, Serialize)]
@@ -15,11 +20,199 @@ pub struct PrinterInfo {
     pub state: String,
 }
 
+/// Lifecycle of a submitted print job
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PrintJobState {
+    Queued,
+    Printing,
+    Done,
+    Error,
+    Cancelled,
+}
+
+/// A submitted print job, tracked independently of the blocking OS spooler call
+#[derive(Debug, Clone, Serialize)]
+pub struct PrintJob {
+    pub job_id: String,
+    pub printer_name: Option<String>,
+    pub submitted_at: String,
+    pub state: PrintJobState,
+    pub error: Option<String>,
+}
+
+/// Messages sent to the print worker thread
+enum PrinterCommand {
+    PrintPhoto { job_id: String, image_bytes: Vec<u8>, printer_name: Option<String> },
+    PrintTestPage { job_id: String, printer_name: String },
+}
+
+/// Printer state managed by Tauri - holds a channel to the print worker
+/// thread and the registry of jobs it reports into.
+pub struct PrinterState {
+    sender: Mutex<Option<Sender<PrinterCommand>>>,
+    jobs: Arc<Mutex<HashMap<String, PrintJob>>>,
+}
+
+impl Default for PrinterState {
+    fn default() -> Self {
+        Self {
+            sender: Mutex::new(None),
+            jobs: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+}
+
+fn next_job_id() -> String {
+    static NEXT: AtomicU64 = AtomicU64::new(1);
+    format!("job-{}", NEXT.fetch_add(1, Ordering::Relaxed))
+}
+
+fn now_str() -> String {
+    chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string()
+}
+
+fn set_job_state(jobs: &Arc<Mutex<HashMap<String, PrintJob>>>, job_id: &str, state: PrintJobState, error: Option<String>) {
+    if let Ok(mut jobs) = jobs.lock() {
+        if let Some(job) = jobs.get_mut(job_id) {
+            job.state = state;
+            job.error = error;
+        }
+    }
+}
+
+/// Move a queued job to `Printing` under a single lock, so a concurrent
+/// `cancel_print_job` either wins (returns `false`) or sees it already started.
+fn try_start_job(jobs: &Arc<Mutex<HashMap<String, PrintJob>>>, job_id: &str) -> bool {
+    let Ok(mut jobs) = jobs.lock() else { return false };
+    match jobs.get_mut(job_id) {
+        Some(job) if job.state == PrintJobState::Queued => {
+            job.state = PrintJobState::Printing;
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Submission order of a job, taken from the counter in its `job-N` id
+fn job_seq(job: &PrintJob) -> u64 {
+    job.job_id.trim_start_matches("job-").parse().unwrap_or(0)
+}
+
+/// Print worker thread. Runs each queued job's blocking spooler call and
+/// records the outcome on the job for `get_print_job_status`/`list_print_jobs` to poll.
+fn printer_thread(receiver: Receiver<PrinterCommand>, jobs: Arc<Mutex<HashMap<String, PrintJob>>>) {
+    while let Ok(cmd) = receiver.recv() {
+        match cmd {
+            PrinterCommand::PrintPhoto { job_id, image_bytes, printer_name } => {
+                if !try_start_job(&jobs, &job_id) {
+                    continue;
+                }
+
+                let system_printers = printers::get_printers();
+                let printer = if let Some(name) = &printer_name {
+                    system_printers
+                        .into_iter()
+                        .find(|p| &p.name == name || &p.system_name == name)
+                } else {
+                    printers::get_default_printer()
+                };
+
+                match printer {
+                    Some(p) => {
+                        let options = PrinterJobOptions {
+                            name: Some("ChronoSnap Photo"),
+                            raw_properties: &[],
+                        };
+                        match p.print(&image_bytes, options) {
+                            Ok(_) => set_job_state(&jobs, &job_id, PrintJobState::Done, None),
+                            Err(e) => set_job_state(
+                                &jobs, &job_id, PrintJobState::Error,
+                                Some(format!("Failed to print photo: {:?}", e)),
+                            ),
+                        }
+                    }
+                    None => set_job_state(&jobs, &job_id, PrintJobState::Error, Some("No printer found".to_string())),
+                }
+            }
+
+            PrinterCommand::PrintTestPage { job_id, printer_name } => {
+                if !try_start_job(&jobs, &job_id) {
+                    continue;
+                }
+
+                let system_printers = printers::get_printers();
+                let printer = system_printers
+                    .into_iter()
+                    .find(|p| p.name == printer_name || p.system_name == printer_name);
+
+                match printer {
+                    Some(p) => {
+                        let test_content = format!(
+                            r#"
+ChronoSnap Printer Test Page
+=============================
+
+Printer: {}
+Driver: {}
+Status: {:?}
+
+If you can read this clearly, your printer is working correctly!
+
+Colors: [Black] [Cyan] [Magenta] [Yellow]
+
+Test printed at: {}
+
+ChronoSnap Photobooth System
+"#,
+                            p.name,
+                            p.driver_name,
+                            p.state,
+                            chrono::Local::now().format("%Y-%m-%d %H:%M:%S")
+                        );
+
+                        let options = PrinterJobOptions {
+                            name: Some("ChronoSnap Test Page"),
+                            raw_properties: &[],
+                        };
+
+                        match p.print(test_content.as_bytes(), options) {
+                            Ok(_) => set_job_state(&jobs, &job_id, PrintJobState::Done, None),
+                            Err(e) => set_job_state(
+                                &jobs, &job_id, PrintJobState::Error,
+                                Some(format!("Failed to print: {:?}", e)),
+                            ),
+                        }
+                    }
+                    None => set_job_state(
+                        &jobs, &job_id, PrintJobState::Error,
+                        Some(format!("Printer '{}' not found", printer_name)),
+                    ),
+                }
+            }
+        }
+    }
+}
+
+/// Ensure the print worker thread is running and get its sender
+fn get_or_create_sender(state: &PrinterState) -> Result<Sender<PrinterCommand>, String> {
+    let mut sender_guard = state.sender.lock().map_err(|e| format!("Lock error: {}", e))?;
+
+    if sender_guard.is_none() {
+        let (tx, rx) = mpsc::channel();
+        let jobs = state.jobs.clone();
+        thread::spawn(move || printer_thread(rx, jobs));
+        *sender_guard = Some(tx);
+    }
+
+    sender_guard.clone().ok_or_else(|| "Failed to get sender".to_string())
+}
+
 /// Get list of all available printers
 #[command]
 pub fn get_printers() -> Result<Vec<PrinterInfo>, String> {
     let system_printers = printers::get_printers();
-    
+
     let printers_info: Vec<PrinterInfo> = system_printers
         .into_iter()
         .map(|p| PrinterInfo {
@@ -32,7 +225,7 @@ pub fn get_printers() -> Result<Vec<PrinterInfo>, String> {
             state: format!("{:?}", p.state),
         })
         .collect();
-    
+
     Ok(printers_info)
 }
 
@@ -53,61 +246,34 @@ pub fn get_default_printer() -> Result<Option<PrinterInfo>, String> {
     }
 }
 
-/// Print a test page to the specified printer
+/// Queue a test page print job on the specified printer and return its job id
 #[command]
-pub fn print_test_page(printer_name: String) -> Result<String, String> {
-    // Find the printer by name
-    let system_printers = printers::get_printers();
-    let printer = system_printers
-        .into_iter()
-        .find(|p| p.name == printer_name || p.system_name == printer_name);
-    
-    match printer {
-        Some(p) => {
-            // Create a simple test page content
-            let test_content = format!(
-                r#"
-ChronoSnap Printer Test Page
-=============================
-
-Printer: {}
-Driver: {}
-Status: {:?}
-
-If you can read this clearly, your printer is working correctly!
+pub fn print_test_page(state: State<'_, PrinterState>, printer_name: String) -> Result<String, String> {
+    let job_id = next_job_id();
+    let job = PrintJob {
+        job_id: job_id.clone(),
+        printer_name: Some(printer_name.clone()),
+        submitted_at: now_str(),
+        state: PrintJobState::Queued,
+        error: None,
+    };
+    state.jobs.lock().map_err(|e| format!("Lock error: {}", e))?.insert(job_id.clone(), job);
 
-Colors: [Black] [Cyan] [Magenta] [Yellow]
+    let sender = get_or_create_sender(&state)?;
+    sender.send(PrinterCommand::PrintTestPage { job_id: job_id.clone(), printer_name })
+        .map_err(|e| format!("Failed to send command: {}", e))?;
 
-Test printed at: {}
-
-ChronoSnap Photobooth System
-"#,
-                p.name,
-                p.driver_name,
-                p.state,
-                chrono::Local::now().format("%Y-%m-%d %H:%M:%S")
-            );
-            
-            // Create print job options with the job name
-            let options = PrinterJobOptions {
-                name: Some("ChronoSnap Test Page"),
-                raw_properties: &[],
-            };
-            
-            // Print the test content
-            match p.print(test_content.as_bytes(), options) {
-                Ok(_) => Ok(format!("Test page sent to printer: {}", p.name)),
-                Err(e) => Err(format!("Failed to print: {:?}", e)),
-            }
-        }
-        None => Err(format!("Printer '{}' not found", printer_name)),
-    }
+    Ok(job_id)
 }
 
-/// Print a photo to the specified printer (or default if not specified)
-/// Takes base64 encoded image data (JPEG)
+/// Queue a photo print job on the specified printer (or default if not
+/// specified) and return its job id. Takes base64 encoded image data (JPEG).
 #[command]
-pub fn print_photo(image_data: String, printer_name: Option<String>) -> Result<String, String> {
+pub fn print_photo(
+    state: State<'_, PrinterState>,
+    image_data: String,
+    printer_name: Option<String>,
+) -> Result<String, String> {
     // Remove data URL prefix if present
     let base64_data = if image_data.starts_with("data:image") {
         image_data
@@ -117,33 +283,60 @@ pub fn print_photo(image_data: String, printer_name: Option<String>) -> Result<S
     } else {
         &image_data
     };
-    
+
     // Decode base64 to bytes
     let image_bytes = base64::Engine::decode(&base64::engine::general_purpose::STANDARD, base64_data)
         .map_err(|e| format!("Failed to decode image: {}", e))?;
-    
-    // Get the printer
-    let system_printers = printers::get_printers();
-    let printer = if let Some(name) = printer_name {
-        system_printers
-            .into_iter()
-            .find(|p| p.name == name || p.system_name == name)
-    } else {
-        printers::get_default_printer()
+
+    let job_id = next_job_id();
+    let job = PrintJob {
+        job_id: job_id.clone(),
+        printer_name: printer_name.clone(),
+        submitted_at: now_str(),
+        state: PrintJobState::Queued,
+        error: None,
     };
-    
-    match printer {
-        Some(p) => {
-            let options = PrinterJobOptions {
-                name: Some("ChronoSnap Photo"),
-                raw_properties: &[],
-            };
-            
-            match p.print(&image_bytes, options) {
-                Ok(_) => Ok(format!("Photo sent to printer: {}", p.name)),
-                Err(e) => Err(format!("Failed to print photo: {:?}", e)),
-            }
+    state.jobs.lock().map_err(|e| format!("Lock error: {}", e))?.insert(job_id.clone(), job);
+
+    let sender = get_or_create_sender(&state)?;
+    sender.send(PrinterCommand::PrintPhoto { job_id: job_id.clone(), image_bytes, printer_name })
+        .map_err(|e| format!("Failed to send command: {}", e))?;
+
+    Ok(job_id)
+}
+
+/// Report a single print job's current state, printer name, submit time, and error detail
+#[command]
+pub fn get_print_job_status(state: State<'_, PrinterState>, job_id: String) -> Result<PrintJob, String> {
+    state.jobs.lock().map_err(|e| format!("Lock error: {}", e))?
+        .get(&job_id)
+        .cloned()
+        .ok_or_else(|| format!("Unknown print job: {}", job_id))
+}
+
+/// List every print job submitted this session, most recent first
+#[command]
+pub fn list_print_jobs(state: State<'_, PrinterState>) -> Result<Vec<PrintJob>, String> {
+    let mut jobs: Vec<PrintJob> = state.jobs.lock().map_err(|e| format!("Lock error: {}", e))?
+        .values()
+        .cloned()
+        .collect();
+    jobs.sort_by(|a, b| b.submitted_at.cmp(&a.submitted_at).then_with(|| job_seq(b).cmp(&job_seq(a))));
+    Ok(jobs)
+}
+
+/// Drop a not-yet-started job from the queue. Jobs that are already printing
+/// or finished cannot be cancelled.
+#[command]
+pub fn cancel_print_job(state: State<'_, PrinterState>, job_id: String) -> Result<(), String> {
+    let mut jobs = state.jobs.lock().map_err(|e| format!("Lock error: {}", e))?;
+
+    match jobs.get_mut(&job_id) {
+        Some(job) if job.state == PrintJobState::Queued => {
+            job.state = PrintJobState::Cancelled;
+            Ok(())
         }
-        None => Err("No printer found".to_string()),
+        Some(_) => Err("Job has already started printing and cannot be cancelled".to_string()),
+        None => Err(format!("Unknown print job: {}", job_id)),
     }
 }